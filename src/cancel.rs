@@ -0,0 +1,87 @@
+use bevy::{
+    ecs::{
+        system::{Command, EntityCommands},
+        world::EntityMut,
+    },
+    hierarchy::Children,
+    prelude::{Entity, World},
+};
+
+use crate::{FrameCountDelete, QueueDelete, TimerDelete};
+
+/// Removes QueueDelete, TimerDelete, and FrameCountDelete from the given
+/// entity, rescuing it from a pending deletion
+#[derive(Debug)]
+struct CancelQueueDelete(Entity);
+
+/// Removes QueueDelete, TimerDelete, and FrameCountDelete from the given
+/// entity and all of its children recursively
+#[derive(Debug)]
+struct CancelQueueDeleteRecursive(Entity);
+
+fn cancel_queue_delete(world: &mut World, entity: Entity) {
+    if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+        entity_mut.remove::<QueueDelete>();
+        entity_mut.remove::<TimerDelete>();
+        entity_mut.remove::<FrameCountDelete>();
+    }
+}
+
+fn cancel_queue_delete_recursive(world: &mut World, entity: Entity) {
+    if let Some(children) = world.get::<Children>(entity).cloned() {
+        for e in children.into_iter() {
+            cancel_queue_delete_recursive(world, *e);
+        }
+    }
+
+    cancel_queue_delete(world, entity);
+}
+
+impl Command for CancelQueueDelete {
+    fn write(self, world: &mut World) {
+        cancel_queue_delete(world, self.0);
+    }
+}
+
+impl Command for CancelQueueDeleteRecursive {
+    fn write(self, world: &mut World) {
+        cancel_queue_delete_recursive(world, self.0);
+    }
+}
+
+/// Trait that holds functions for rescuing an entity from a pending
+/// QueueDelete, TimerDelete, or FrameCountDelete before CoreStage::Last reaps it
+pub trait CancelQueueDeleteExt {
+    fn cancel_queue_delete(&mut self);
+    fn cancel_queue_delete_recursive(&mut self);
+}
+
+impl<'w, 's, 'a> CancelQueueDeleteExt for EntityCommands<'w, 's, 'a> {
+    fn cancel_queue_delete(&mut self) {
+        let entity = self.id();
+        self.commands().add(CancelQueueDelete(entity));
+    }
+
+    fn cancel_queue_delete_recursive(&mut self) {
+        let entity = self.id();
+        self.commands().add(CancelQueueDeleteRecursive(entity));
+    }
+}
+
+impl<'w> CancelQueueDeleteExt for EntityMut<'w> {
+    fn cancel_queue_delete(&mut self) {
+        let entity = self.id();
+        unsafe {
+            cancel_queue_delete(self.world_mut(), entity);
+            self.update_location();
+        }
+    }
+
+    fn cancel_queue_delete_recursive(&mut self) {
+        let entity = self.id();
+        unsafe {
+            cancel_queue_delete_recursive(self.world_mut(), entity);
+            self.update_location();
+        }
+    }
+}
@@ -2,10 +2,10 @@ use std::time::Duration;
 
 use bevy::{
     core::{Time, Timer},
-    ecs::{reflect::ReflectComponent},
+    ecs::{event::Events, reflect::ReflectComponent},
     prelude::{
-        Added, Commands, Component, CoreStage, Entity,
-        ParallelSystemDescriptorCoercion, Plugin, Query, Res, SystemLabel, With,
+        Added, Commands, Component, CoreStage, Entity, EventWriter,
+        ParallelSystemDescriptorCoercion, Plugin, Query, Res, SystemLabel, With, World,
     },
     reflect::{FromReflect, Reflect},
 };
@@ -13,6 +13,12 @@ use bevy::{
 mod recursive;
 pub use recursive::{QueueDespawnRecursiveExt, queue_despawn_with_children_recursive};
 
+mod cancel;
+pub use cancel::CancelQueueDeleteExt;
+
+mod state_scoped;
+pub use state_scoped::{StateScoped, StateScopedDeletionAppExt};
+
 #[cfg(feature = "ref_delete")]
 pub mod ref_delete;
 
@@ -20,7 +26,8 @@ pub struct BevyQueueDeletePlugin;
 
 impl Plugin for BevyQueueDeletePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.register_type::<QueueDelete>()
+        app.add_event::<EntityDeletionEvent>()
+            .register_type::<QueueDelete>()
             .add_system_to_stage(CoreStage::Last, queue_delete_system.label(QueueDelete))
             .register_type::<TimerDelete>()
             .add_system(timer_delete_system)
@@ -34,6 +41,40 @@ impl Plugin for BevyQueueDeletePlugin {
     }
 }
 
+/// Fired as entities move through the deletion pipeline, so other systems can
+/// react to the exact moment an entity is committed for deletion instead of
+/// having it despawn silently inside `CoreStage::Last`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityDeletionEvent {
+    /// An entity just had [QueueDelete] inserted onto it.
+    QueuedForDeletion(Entity),
+    /// An entity was just despawned by [queue_delete_system].
+    Despawned(Entity),
+}
+
+/// Inserts [QueueDelete] onto `entity` and sends the matching
+/// [EntityDeletionEvent::QueuedForDeletion]. All `QueueDelete` insertion
+/// points (timers, frame counts, ref-counting, recursive despawn, state-scoped
+/// deletion, ...) should go through this helper or its [World] counterpart
+/// [queue_delete_world] so the event is never missed.
+pub(crate) fn queue_delete(
+    commands: &mut Commands,
+    events: &mut EventWriter<EntityDeletionEvent>,
+    entity: Entity,
+) {
+    commands.entity(entity).insert(QueueDelete);
+    events.send(EntityDeletionEvent::QueuedForDeletion(entity));
+}
+
+/// [World]-based equivalent of [queue_delete], for call sites (like
+/// `recursive.rs`) that work directly on a [World] rather than [Commands].
+pub(crate) fn queue_delete_world(world: &mut World, entity: Entity) {
+    world.entity_mut(entity).insert(QueueDelete);
+    world
+        .resource_mut::<Events<EntityDeletionEvent>>()
+        .send(EntityDeletionEvent::QueuedForDeletion(entity));
+}
+
 /// Automatically despawns entities in CoreStage::Last
 /// Use QueueDelete as a Label so that cleanup can happen right before
 /// Use queue_despawn_recursive and queue_despawn_descendents to insert into children
@@ -44,9 +85,14 @@ impl Plugin for BevyQueueDeletePlugin {
 #[component(storage = "SparseSet")]
 pub struct QueueDelete;
 
-fn queue_delete_system(mut commands: Commands, query: Query<Entity, With<QueueDelete>>) {
+fn queue_delete_system(
+    mut commands: Commands,
+    mut events: EventWriter<EntityDeletionEvent>,
+    query: Query<Entity, With<QueueDelete>>,
+) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
+        events.send(EntityDeletionEvent::Despawned(entity));
     }
 }
 
@@ -65,17 +111,34 @@ impl TimerDelete {
             timer: Timer::default(),
         }
     }
+
+    /// Restarts the countdown from zero. This only prevents the deletion if
+    /// it runs before `timer_delete_system` ticks the timer and inserts
+    /// `QueueDelete` this frame; once that has happened, pair it with
+    /// [crate::CancelQueueDeleteExt::cancel_queue_delete] to actually rescue
+    /// the entity.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Pushes the deletion further out by adding `extra` to the remaining
+    /// duration without losing the timer's current elapsed progress.
+    pub fn extend(&mut self, extra: Duration) {
+        self.duration += extra;
+        self.timer.set_duration(self.duration);
+    }
 }
 
 fn timer_delete_system(
     mut commands: Commands,
+    mut events: EventWriter<EntityDeletionEvent>,
     time: Res<Time>,
     mut query: Query<(Entity, &mut TimerDelete)>,
 ) {
     for (entity, mut timer) in query.iter_mut() {
         timer.timer.tick(time.delta());
         if timer.timer.just_finished() {
-            commands.entity(entity).insert(QueueDelete);
+            queue_delete(&mut commands, &mut events, entity);
         }
     }
 }
@@ -92,14 +155,26 @@ fn timer_start_fn(mut query: Query<&mut TimerDelete, Added<TimerDelete>>) {
 #[reflect(Component)]
 pub struct FrameCountDelete(pub u64);
 
+impl FrameCountDelete {
+    /// Re-arms the countdown to `frames`. This only prevents the deletion if
+    /// it runs before `frame_count_delete_system` decrements the counter and
+    /// inserts `QueueDelete` this frame; once that has happened, pair it
+    /// with [crate::CancelQueueDeleteExt::cancel_queue_delete] to actually
+    /// rescue the entity.
+    pub fn rearm(&mut self, frames: u64) {
+        self.0 = frames;
+    }
+}
+
 pub fn frame_count_delete_system(
     mut commands: Commands,
+    mut events: EventWriter<EntityDeletionEvent>,
     mut query: Query<(Entity, &mut FrameCountDelete)>,
 ) {
     for (entity, mut frames) in query.iter_mut() {
-        frames.0 -= 1;
-        if frames.0 <= 0 {
-            commands.entity(entity).insert(QueueDelete);
+        frames.0 = frames.0.saturating_sub(1);
+        if frames.0 == 0 {
+            queue_delete(&mut commands, &mut events, entity);
         }
     }
 }
\ No newline at end of file
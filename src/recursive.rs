@@ -1,6 +1,6 @@
 use bevy::{prelude::{Entity, World}, ecs::{system::{Command, EntityCommands}, world::EntityMut}, hierarchy::Children};
 
-use crate::QueueDelete;
+use crate::queue_delete_world;
 
 
 /// Inserts QueueDelete into the given entity and all its children recursively
@@ -23,7 +23,7 @@ fn queue_despawn_with_children_recursive_inner(world: &mut World, entity: Entity
         }
     }
 
-    world.entity_mut(entity).insert(QueueDelete);
+    queue_delete_world(world, entity);
 }
 
 fn queue_despawn_children(world: &mut World, entity: Entity) {
@@ -32,8 +32,6 @@ fn queue_despawn_children(world: &mut World, entity: Entity) {
             queue_despawn_with_children_recursive_inner(world, *e);
         }
     }
-
-    world.entity_mut(entity).insert(QueueDelete);
 }
 
 impl Command for QueueDespawnRecursive {
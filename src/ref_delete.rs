@@ -2,20 +2,25 @@ use std::sync::Arc;
 
 use bevy::{
     ecs::{reflect::ReflectComponent},
-    prelude::{App, Commands, Component, Entity, Plugin, Res, Query},
+    hierarchy::Parent,
+    prelude::{App, Commands, Component, Entity, EventWriter, Plugin, Res, Query},
     reflect::{FromReflect, Reflect},
     utils::HashMap,
 };
 use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::{Mutex, RwLock};
 
-use crate::QueueDelete;
+use crate::{queue_delete, EntityDeletionEvent};
 
 pub struct RefEntityPlugin;
 
 impl Plugin for RefEntityPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<RefEntityServer>()
+        // `add_event` is idempotent, so this is safe whether or not
+        // `BevyQueueDeletePlugin` (which also registers this event) has
+        // already been added.
+        app.add_event::<EntityDeletionEvent>()
+            .init_resource::<RefEntityServer>()
             .add_system(free_unused_entities)
             .add_system(mark_unused_entities);
     }
@@ -27,6 +32,7 @@ impl Plugin for RefEntityPlugin {
 pub struct RefEntityServer {
     channel: Arc<RefChangeChannel>,
     ref_counts: Arc<RwLock<HashMap<Entity, usize>>>,
+    recursive_counts: Arc<RwLock<HashMap<Entity, usize>>>,
     mark_unused_assets: Arc<Mutex<Vec<Entity>>>,
 }
 
@@ -38,29 +44,93 @@ impl RefEntityServer {
         let sender = self.channel.sender.clone();
         RefEntityHandle::strong(id.into(), sender)
     }
+
+    /// Gets a strong handle that additionally protects `id`'s `Children`
+    /// hierarchy: while this handle (or any clone of it) is alive, every
+    /// descendant of `id` is treated as still-referenced even if its own
+    /// ref count is `0`. Use this to ref-count a logical object made up of
+    /// several entities (a rigged model, a composed prefab) as a unit.
+    pub fn get_handle_recursive<I: Into<Entity>>(&self, id: I) -> RefEntityHandle {
+        let sender = self.channel.sender.clone();
+        RefEntityHandle::strong_recursive(id.into(), sender)
+    }
+
+    /// Returns the number of live strong handles for `entity` as of the last
+    /// time `mark_unused_entities` drained the ref-change channel. Entities
+    /// that have never had a strong handle return `0`.
+    pub fn get_ref_count(&self, entity: Entity) -> usize {
+        self.ref_counts.read().get(&entity).copied().unwrap_or(0)
+    }
+
+    /// Alias of [RefEntityServer::get_ref_count] using the naming of
+    /// [RefEntityHandle]'s strong/weak distinction.
+    pub fn strong_handle_count(&self, entity: Entity) -> usize {
+        self.get_ref_count(entity)
+    }
 }
 
 // *****************************************************************************************
 // Systems
 // *****************************************************************************************
-fn free_unused_entities(mut commands: Commands, server: Res<RefEntityServer>, valid_query: Query<Entity>) {
+fn free_unused_entities(
+    mut commands: Commands,
+    mut events: EventWriter<EntityDeletionEvent>,
+    server: Res<RefEntityServer>,
+    valid_query: Query<Entity>,
+    parent_query: Query<&Parent>,
+) {
     let mut potential_frees = server.mark_unused_assets.lock();
     if !potential_frees.is_empty() {
-        let ref_counts = server.ref_counts.read();
+        let mut ref_counts = server.ref_counts.write();
+        let mut recursive_counts = server.recursive_counts.write();
         for potential_free in potential_frees.drain(..) {
+            // Re-read the live count: the entity may have been handed a new
+            // strong handle between being marked and this system running, in
+            // which case it must not be freed.
             if let Some(&0) = ref_counts.get(&potential_free) {
+                if has_live_recursive_ancestor(potential_free, &parent_query, &recursive_counts) {
+                    continue;
+                }
                 if valid_query.get(potential_free).is_ok() {
-                    commands.entity(potential_free).insert(QueueDelete);
+                    queue_delete(&mut commands, &mut events, potential_free);
                 }
+                ref_counts.remove(&potential_free);
+                // The entity is gone for good (or about to be); drop its
+                // recursive-handle bookkeeping too so a churning stream of
+                // recursively ref-counted entities doesn't leak map entries.
+                recursive_counts.remove(&potential_free);
             }
         }
     }
 }
 
+/// Walks up the `Parent` chain from `entity`, returning `true` if any
+/// ancestor currently has a nonzero recursive strong-handle count.
+fn has_live_recursive_ancestor(
+    entity: Entity,
+    parent_query: &Query<&Parent>,
+    recursive_counts: &HashMap<Entity, usize>,
+) -> bool {
+    let mut current = entity;
+    while let Ok(parent) = parent_query.get(current) {
+        let parent_entity = parent.get();
+        if recursive_counts.get(&parent_entity).copied().unwrap_or(0) > 0 {
+            return true;
+        }
+        current = parent_entity;
+    }
+    false
+}
+
 fn mark_unused_entities(server: Res<RefEntityServer>) {
     let receiver = &server.channel.receiver;
+    // Acquire locks in the same order as `free_unused_entities`
+    // (mark_unused_assets -> ref_counts -> recursive_counts) to avoid a lock-order
+    // inversion between the two systems, which run with no ordering constraint
+    // between them and could otherwise deadlock under contention.
+    let mut potential_frees = server.mark_unused_assets.lock();
     let mut ref_counts = server.ref_counts.write();
-    let mut potential_frees = None;
+    let mut recursive_counts = server.recursive_counts.write();
     loop {
         let ref_change = match receiver.try_recv() {
             Ok(ref_change) => ref_change,
@@ -72,13 +142,26 @@ fn mark_unused_entities(server: Res<RefEntityServer>) {
             RefChange::Decrement(handle_id) => {
                 let entry = ref_counts.entry(handle_id.clone()).or_insert(0);
                 *entry -= 1;
-                if *entry <= 0 {
-                    potential_frees
-                        .get_or_insert_with(|| server.mark_unused_assets.lock())
-                        .push(handle_id.clone());
-                    ref_counts.remove(&handle_id);
+                if *entry == 0 {
+                    // Leave the entry in the map at 0 rather than removing it
+                    // here; free_unused_entities re-checks the live count so
+                    // an entity revived before it runs is left alone.
+                    potential_frees.push(handle_id.clone());
                 }
             }
+            RefChange::IncrementRecursive(handle_id) => {
+                *ref_counts.entry(handle_id).or_insert(0) += 1;
+                *recursive_counts.entry(handle_id).or_insert(0) += 1;
+            }
+            RefChange::DecrementRecursive(handle_id) => {
+                let entry = ref_counts.entry(handle_id.clone()).or_insert(0);
+                *entry -= 1;
+                if *entry == 0 {
+                    potential_frees.push(handle_id.clone());
+                }
+                let recursive_entry = recursive_counts.entry(handle_id).or_insert(0);
+                *recursive_entry -= 1;
+            }
         }
     }
 }
@@ -89,6 +172,9 @@ fn mark_unused_entities(server: Res<RefEntityServer>) {
 enum RefCompHandleType {
     Weak,
     Strong(Sender<RefChange>),
+    /// Like `Strong`, but also protects the entity's `Children` hierarchy
+    /// from being freed while this handle is alive.
+    StrongRecursive(Sender<RefChange>),
 }
 
 impl core::fmt::Debug for RefCompHandleType {
@@ -96,6 +182,7 @@ impl core::fmt::Debug for RefCompHandleType {
         match self {
             RefCompHandleType::Weak => f.write_str("Weak"),
             RefCompHandleType::Strong(_) => f.write_str("Strong"),
+            RefCompHandleType::StrongRecursive(_) => f.write_str("StrongRecursive"),
         }
     }
 }
@@ -117,6 +204,16 @@ impl RefEntityHandle {
         }
     }
 
+    fn strong_recursive(entity: Entity, ref_change_sender: Sender<RefChange>) -> Self {
+        ref_change_sender
+            .send(RefChange::IncrementRecursive(entity.clone()))
+            .unwrap();
+        Self {
+            entity,
+            handle_type: RefCompHandleType::StrongRecursive(ref_change_sender),
+        }
+    }
+
     #[inline]
     pub fn weak(entity: Entity) -> Self {
         Self {
@@ -138,7 +235,16 @@ impl RefEntityHandle {
     }
 
     pub fn is_strong(&self) -> bool {
-        matches!(self.handle_type, RefCompHandleType::Strong(_))
+        matches!(
+            self.handle_type,
+            RefCompHandleType::Strong(_) | RefCompHandleType::StrongRecursive(_)
+        )
+    }
+
+    /// Whether this handle also protects the entity's `Children` hierarchy
+    /// (see [RefEntityServer::get_handle_recursive]).
+    pub fn is_recursive(&self) -> bool {
+        matches!(self.handle_type, RefCompHandleType::StrongRecursive(_))
     }
 
     /// Makes this handle Strong if it wasn't already.
@@ -167,6 +273,9 @@ impl Drop for RefEntityHandle {
                 // stopped
                 let _ = sender.send(RefChange::Decrement(self.entity.clone()));
             }
+            RefCompHandleType::StrongRecursive(ref sender) => {
+                let _ = sender.send(RefChange::DecrementRecursive(self.entity.clone()));
+            }
             RefCompHandleType::Weak => {}
         }
     }
@@ -193,6 +302,9 @@ impl Clone for RefEntityHandle {
             RefCompHandleType::Strong(ref sender) => {
                 RefEntityHandle::strong(self.entity.clone(), sender.clone())
             }
+            RefCompHandleType::StrongRecursive(ref sender) => {
+                RefEntityHandle::strong_recursive(self.entity.clone(), sender.clone())
+            }
             RefCompHandleType::Weak => RefEntityHandle::weak(self.entity.clone()),
         }
     }
@@ -201,6 +313,8 @@ impl Clone for RefEntityHandle {
 enum RefChange {
     Increment(Entity),
     Decrement(Entity),
+    IncrementRecursive(Entity),
+    DecrementRecursive(Entity),
 }
 
 #[derive(Clone)]
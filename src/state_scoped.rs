@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::reflect::ReflectComponent,
+    prelude::{
+        App, Commands, Component, CoreStage, Entity, ParallelSystemDescriptorCoercion, Query,
+        Res, State,
+    },
+    reflect::{FromReflect, Reflect},
+};
+
+use crate::{EntityDeletionEvent, QueueDelete, QueueDespawnRecursiveExt};
+
+/// Marks an entity as belonging to the state `S::variant` it was spawned in.
+/// Once the app transitions away from that state the entity is automatically
+/// queued for deletion via [QueueDelete], mirroring the "NonPersistent" entity
+/// concept some other engines tie to their state/life-cycle stack.
+///
+/// Requires [StateScopedDeletionAppExt::enable_state_scoped_deletion] to be
+/// called for `S` before this component has any effect. Composes with
+/// [crate::QueueDespawnRecursiveExt] so whole subtrees despawn together.
+#[derive(Component, Reflect, FromReflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct StateScoped<S: Send + Sync + 'static>(pub S, #[reflect(ignore)] PhantomData<S>);
+
+impl<S: Send + Sync + 'static> StateScoped<S> {
+    pub fn new(state: S) -> Self {
+        StateScoped(state, PhantomData)
+    }
+}
+
+/// Extension trait for registering state-scoped deletion for a particular
+/// state type `S`.
+pub trait StateScopedDeletionAppExt {
+    /// Enables automatic deletion of [StateScoped<S>] entities whenever the
+    /// app's `State<S>` transitions away from the state they were scoped to.
+    fn enable_state_scoped_deletion<S>(&mut self) -> &mut Self
+    where
+        S: Component + Clone + PartialEq + std::fmt::Debug;
+}
+
+impl StateScopedDeletionAppExt for App {
+    fn enable_state_scoped_deletion<S>(&mut self) -> &mut Self
+    where
+        S: Component + Clone + PartialEq + std::fmt::Debug,
+    {
+        // `add_event` is idempotent, so this is safe whether or not
+        // `BevyQueueDeletePlugin` (which also registers this event) has
+        // already been added; `queue_despawn_recursive` needs it to exist.
+        self.add_event::<EntityDeletionEvent>().add_system_to_stage(
+            CoreStage::Last,
+            queue_state_scoped_deletion::<S>.before(QueueDelete),
+        )
+    }
+}
+
+fn queue_state_scoped_deletion<S: Component + Clone + PartialEq + std::fmt::Debug>(
+    mut commands: Commands,
+    state: Res<State<S>>,
+    query: Query<(Entity, &StateScoped<S>)>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let current = state.current();
+    for (entity, scoped) in query.iter() {
+        if &scoped.0 != current {
+            commands.entity(entity).queue_despawn_recursive();
+        }
+    }
+}